@@ -22,10 +22,12 @@
 //! ```
 
 use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
 use serde::{Deserialize, Serialize};
 
 // Re-export from core library
-use meta_oxide::{extractors, parser};
+use meta_oxide::{extractor::Registry, extractors, mf2, parser, rdf};
 
 /// Initialize panic hook for better error messages in development
 #[wasm_bindgen(start)]
@@ -43,7 +45,7 @@ extern "C" {
 
 /// Complete extraction result containing all metadata formats
 #[wasm_bindgen]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct ExtractionResult {
     /// Standard HTML meta tags
     meta: Option<String>,
@@ -69,6 +71,32 @@ pub struct ExtractionResult {
     rel_links: Option<String>,
 }
 
+impl ExtractionResult {
+    /// Build a result from `(format_name, json_value)` pairs, e.g. as
+    /// produced by [`meta_oxide::extractor::Registry::extract_all`].
+    fn from_results(results: Vec<(String, serde_json::Value)>) -> Self {
+        let mut out = ExtractionResult::default();
+        for (name, value) in results {
+            let json = serde_json::to_string(&value).ok();
+            match name.as_str() {
+                "meta" => out.meta = json,
+                "open_graph" => out.open_graph = json,
+                "twitter" => out.twitter = json,
+                "json_ld" => out.json_ld = json,
+                "microdata" => out.microdata = json,
+                "microformats" => out.microformats = json,
+                "rdfa" => out.rdfa = json,
+                "dublin_core" => out.dublin_core = json,
+                "manifest" => out.manifest = json,
+                "oembed" => out.oembed = json,
+                "rel_links" => out.rel_links = json,
+                _ => {}
+            }
+        }
+        out
+    }
+}
+
 #[wasm_bindgen]
 impl ExtractionResult {
     /// Get standard HTML meta tags as JSON string
@@ -179,68 +207,37 @@ impl ExtractionResult {
 /// ```
 #[wasm_bindgen(js_name = extractAll)]
 pub fn extract_all(html: &str, base_url: Option<String>) -> Result<ExtractionResult, JsValue> {
-    let base = base_url.as_deref();
-
     let document = parser::parse_html(html)
         .map_err(|e| JsValue::from_str(&format!("Parse error: {}", e)))?;
 
-    let meta = extractors::meta::extract(&document, base)
-        .ok()
-        .and_then(|m| serde_json::to_string(&m).ok());
-
-    let open_graph = extractors::open_graph::extract(&document, base)
-        .ok()
-        .and_then(|og| serde_json::to_string(&og).ok());
-
-    let twitter = extractors::twitter::extract(&document, base)
-        .ok()
-        .and_then(|tw| serde_json::to_string(&tw).ok());
-
-    let json_ld = extractors::json_ld::extract(&document, base)
-        .ok()
-        .and_then(|jl| serde_json::to_string(&jl).ok());
-
-    let microdata = extractors::microdata::extract(&document, base)
-        .ok()
-        .and_then(|md| serde_json::to_string(&md).ok());
-
-    let microformats = extractors::microformats::extract(&document, base)
-        .ok()
-        .and_then(|mf| serde_json::to_string(&mf).ok());
-
-    let rdfa = extractors::rdfa::extract(&document, base)
-        .ok()
-        .and_then(|r| serde_json::to_string(&r).ok());
+    let results = Registry::default().extract_all(&document, base_url.as_deref());
+    Ok(ExtractionResult::from_results(results))
+}
 
-    let dublin_core = extractors::dublin_core::extract(&document, base)
-        .ok()
-        .and_then(|dc| serde_json::to_string(&dc).ok());
+/// Extract only the named formats (e.g. `["meta", "open_graph"]`)
+///
+/// Cheaper than [`extract_all`] when a caller only needs a couple of
+/// formats, since unselected extractors never run.
+#[wasm_bindgen(js_name = extractSelected)]
+pub fn extract_selected(
+    html: &str,
+    base_url: Option<String>,
+    formats: Vec<String>,
+) -> Result<ExtractionResult, JsValue> {
+    let document = parser::parse_html(html)
+        .map_err(|e| JsValue::from_str(&format!("Parse error: {}", e)))?;
 
-    let manifest = extractors::manifest::extract(&document, base)
-        .ok()
-        .and_then(|m| serde_json::to_string(&m).ok());
+    let registry = Registry::default();
+    let names: Vec<&str> = formats.iter().map(String::as_str).collect();
+    let base = base_url.as_deref();
 
-    let oembed = extractors::oembed::extract(&document, base)
-        .ok()
-        .and_then(|oe| serde_json::to_string(&oe).ok());
+    let results = registry
+        .select(&names)
+        .into_iter()
+        .filter_map(|e| e.extract(&document, base).ok().map(|v| (e.name().to_string(), v)))
+        .collect();
 
-    let rel_links = extractors::rel_links::extract(&document, base)
-        .ok()
-        .and_then(|rl| serde_json::to_string(&rl).ok());
-
-    Ok(ExtractionResult {
-        meta,
-        open_graph,
-        twitter,
-        json_ld,
-        microdata,
-        microformats,
-        rdfa,
-        dublin_core,
-        manifest,
-        oembed,
-        rel_links,
-    })
+    Ok(ExtractionResult::from_results(results))
 }
 
 /// Extract standard HTML meta tags
@@ -386,6 +383,299 @@ pub fn extract_rel_links(html: &str, base_url: Option<String>) -> Result<String,
         .map_err(|e| JsValue::from_str(&format!("JSON error: {}", e)))
 }
 
+/// Extract every registered format from `html`, invoking `callback` once
+/// per format as it completes instead of buffering all of them up front
+///
+/// `callback` is called with a single `{format, data}` object per format;
+/// formats that fail to extract are skipped rather than passed to the
+/// callback.
+#[wasm_bindgen(js_name = extractAllStreaming)]
+pub fn extract_all_streaming(
+    html: &str,
+    base_url: Option<String>,
+    callback: &js_sys::Function,
+) -> Result<(), JsValue> {
+    let document = parser::parse_html(html)
+        .map_err(|e| JsValue::from_str(&format!("Parse error: {}", e)))?;
+
+    let registry = Registry::default();
+    for (format, result) in registry.extract_stream(&document, base_url.as_deref(), None) {
+        let Ok(data) = result else { continue };
+        let payload = serde_json::json!({ "format": format, "data": data });
+        let js_payload = serde_wasm_bindgen::to_value(&payload)
+            .map_err(|e| JsValue::from_str(&format!("JSON error: {}", e)))?;
+        callback
+            .call1(&JsValue::NULL, &js_payload)
+            .map_err(|e| JsValue::from_str(&format!("callback error: {:?}", e)))?;
+    }
+
+    Ok(())
+}
+
+/// Extract microformats2 data in the canonical IndieWeb JSON shape
+/// (`{ "items": [...], "rels": {...}, "rel-urls": {...} }`)
+#[wasm_bindgen(js_name = extractMicroformats2)]
+pub fn extract_microformats2(html: &str, base_url: Option<String>) -> Result<String, JsValue> {
+    let document = parser::parse_html(html)
+        .map_err(|e| JsValue::from_str(&format!("Parse error: {}", e)))?;
+
+    let mf2_json = mf2::to_mf2_json(&document, base_url.as_deref())
+        .map_err(|e| JsValue::from_str(&format!("Extraction error: {}", e)))?;
+
+    serde_json::to_string(&mf2_json).map_err(|e| JsValue::from_str(&format!("JSON error: {}", e)))
+}
+
+/// Extract JSON-LD, Microdata, and RDFa as one merged RDF graph
+///
+/// # Arguments
+/// * `html` - HTML content to parse
+/// * `base_url` - Optional base URL for resolving relative URLs
+/// * `format` - Either `"turtle"` or `"ntriples"` (defaults to Turtle)
+#[wasm_bindgen(js_name = extractRdf)]
+pub fn extract_rdf(html: &str, base_url: Option<String>, format: Option<String>) -> Result<String, JsValue> {
+    let document = parser::parse_html(html)
+        .map_err(|e| JsValue::from_str(&format!("Parse error: {}", e)))?;
+
+    let graph = rdf::extract_rdf(&document, base_url.as_deref())
+        .map_err(|e| JsValue::from_str(&format!("RDF extraction error: {}", e)))?;
+
+    match format.as_deref() {
+        Some("ntriples") => rdf::to_ntriples(&graph),
+        _ => rdf::to_turtle(&graph),
+    }
+    .map_err(|e| JsValue::from_str(&format!("Serialization error: {}", e)))
+}
+
+/// Run a SPARQL SELECT/ASK query against the RDF graph extracted from `html`
+///
+/// # Returns
+/// A JSON string: `{"type": "select", "variables": [...], "rows": [...]}`
+/// or `{"type": "ask", "value": true|false}`.
+#[wasm_bindgen(js_name = queryRdf)]
+pub fn query_rdf(html: &str, base_url: Option<String>, sparql: &str) -> Result<String, JsValue> {
+    let document = parser::parse_html(html)
+        .map_err(|e| JsValue::from_str(&format!("Parse error: {}", e)))?;
+
+    let graph = rdf::extract_rdf(&document, base_url.as_deref())
+        .map_err(|e| JsValue::from_str(&format!("RDF extraction error: {}", e)))?;
+
+    let result = rdf::query(&graph, sparql)
+        .map_err(|e| JsValue::from_str(&format!("Query error: {}", e)))?;
+
+    let json = match result {
+        rdf::QueryResult::Select { variables, rows } => serde_json::json!({
+            "type": "select",
+            "variables": variables,
+            "rows": rows.iter().map(|row| {
+                row.iter().map(|term| term.as_ref().map(|t| t.to_string())).collect::<Vec<_>>()
+            }).collect::<Vec<_>>(),
+        }),
+        rdf::QueryResult::Ask(value) => serde_json::json!({ "type": "ask", "value": value }),
+    };
+
+    serde_json::to_string(&json).map_err(|e| JsValue::from_str(&format!("JSON error: {}", e)))
+}
+
+/// Fetch `url` and parse the response body as JSON, using the
+/// browser/Deno/Workers global `fetch`.
+async fn fetch_json(url: &str) -> Result<serde_json::Value, JsValue> {
+    let window = web_sys::window().ok_or_else(|| JsValue::from_str("no global `fetch` available"))?;
+    let response: web_sys::Response = JsFuture::from(window.fetch_with_str(url))
+        .await?
+        .dyn_into()
+        .map_err(|_| JsValue::from_str("fetch() did not resolve to a Response"))?;
+
+    if !response.ok() {
+        return Err(JsValue::from_str(&format!(
+            "fetch failed with status {}",
+            response.status()
+        )));
+    }
+
+    let json = JsFuture::from(response.json()?).await?;
+    serde_wasm_bindgen::from_value(json)
+        .map_err(|e| JsValue::from_str(&format!("invalid JSON response: {}", e)))
+}
+
+/// A resolved oEmbed response, fetched from the endpoint discovered in the
+/// page's `<link type="application/json+oembed">`.
+#[wasm_bindgen]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ResolvedOEmbed {
+    oembed_type: Option<String>,
+    html: Option<String>,
+    thumbnail_url: Option<String>,
+    title: Option<String>,
+    author_name: Option<String>,
+}
+
+#[wasm_bindgen]
+impl ResolvedOEmbed {
+    #[wasm_bindgen(getter, js_name = type)]
+    pub fn oembed_type(&self) -> Option<String> {
+        self.oembed_type.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn html(&self) -> Option<String> {
+        self.html.clone()
+    }
+
+    #[wasm_bindgen(getter, js_name = thumbnailUrl)]
+    pub fn thumbnail_url(&self) -> Option<String> {
+        self.thumbnail_url.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn title(&self) -> Option<String> {
+        self.title.clone()
+    }
+
+    #[wasm_bindgen(getter, js_name = authorName)]
+    pub fn author_name(&self) -> Option<String> {
+        self.author_name.clone()
+    }
+}
+
+/// Discover the oEmbed endpoint in `html` and fetch it
+///
+/// Unlike [`extract_oembed`], which only returns the discovered endpoint
+/// URL, this resolves it over the network and parses the oEmbed JSON
+/// response (`type`, `html`, `thumbnail_url`, ...).
+#[wasm_bindgen(js_name = resolveOEmbed)]
+pub async fn resolve_oembed(html: &str, base_url: Option<String>) -> Result<ResolvedOEmbed, JsValue> {
+    let document = parser::parse_html(html)
+        .map_err(|e| JsValue::from_str(&format!("Parse error: {}", e)))?;
+
+    let discovered = extractors::oembed::extract(&document, base_url.as_deref())
+        .map_err(|e| JsValue::from_str(&format!("Discovery error: {}", e)))?;
+    let endpoint = serde_json::to_value(&discovered)
+        .ok()
+        .and_then(|v| v.get("url").and_then(|u| u.as_str()).map(str::to_string))
+        .ok_or_else(|| JsValue::from_str("no oEmbed endpoint discovered"))?;
+
+    let body = fetch_json(&endpoint).await?;
+    Ok(ResolvedOEmbed {
+        oembed_type: body.get("type").and_then(|v| v.as_str()).map(str::to_string),
+        html: body.get("html").and_then(|v| v.as_str()).map(str::to_string),
+        thumbnail_url: body
+            .get("thumbnail_url")
+            .and_then(|v| v.as_str())
+            .map(str::to_string),
+        title: body.get("title").and_then(|v| v.as_str()).map(str::to_string),
+        author_name: body
+            .get("author_name")
+            .and_then(|v| v.as_str())
+            .map(str::to_string),
+    })
+}
+
+/// A resolved Web App Manifest, fetched from the `<link rel="manifest">`
+/// discovered in the page.
+#[wasm_bindgen]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ResolvedManifest {
+    name: Option<String>,
+    short_name: Option<String>,
+    theme_color: Option<String>,
+    display: Option<String>,
+    icons: Vec<String>,
+    shortcuts: Vec<String>,
+}
+
+#[wasm_bindgen]
+impl ResolvedManifest {
+    #[wasm_bindgen(getter)]
+    pub fn name(&self) -> Option<String> {
+        self.name.clone()
+    }
+
+    #[wasm_bindgen(getter, js_name = shortName)]
+    pub fn short_name(&self) -> Option<String> {
+        self.short_name.clone()
+    }
+
+    #[wasm_bindgen(getter, js_name = themeColor)]
+    pub fn theme_color(&self) -> Option<String> {
+        self.theme_color.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn display(&self) -> Option<String> {
+        self.display.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn icons(&self) -> Vec<String> {
+        self.icons.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn shortcuts(&self) -> Vec<String> {
+        self.shortcuts.clone()
+    }
+}
+
+/// Discover the Web App Manifest link in `html` and fetch it
+///
+/// Icon URLs in the manifest are resolved against the manifest's own URL
+/// (not the page's `base_url`), since manifest-relative icon paths are
+/// relative to the manifest document itself.
+#[wasm_bindgen(js_name = resolveManifest)]
+pub async fn resolve_manifest(html: &str, base_url: Option<String>) -> Result<ResolvedManifest, JsValue> {
+    let document = parser::parse_html(html)
+        .map_err(|e| JsValue::from_str(&format!("Parse error: {}", e)))?;
+
+    let discovered = extractors::manifest::extract(&document, base_url.as_deref())
+        .map_err(|e| JsValue::from_str(&format!("Discovery error: {}", e)))?;
+    let manifest_url = serde_json::to_value(&discovered)
+        .ok()
+        .and_then(|v| v.get("href").and_then(|u| u.as_str()).map(str::to_string))
+        .ok_or_else(|| JsValue::from_str("no manifest link discovered"))?;
+
+    let body = fetch_json(&manifest_url).await?;
+
+    let icons = body
+        .get("icons")
+        .and_then(|v| v.as_array())
+        .map(|icons| {
+            icons
+                .iter()
+                .filter_map(|icon| icon.get("src").and_then(|s| s.as_str()))
+                .filter_map(|src| {
+                    meta_oxide::url_utils::resolve_url(Some(&manifest_url), src)
+                        .ok()
+                        .or_else(|| Some(src.to_string()))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(ResolvedManifest {
+        name: body.get("name").and_then(|v| v.as_str()).map(str::to_string),
+        short_name: body
+            .get("short_name")
+            .and_then(|v| v.as_str())
+            .map(str::to_string),
+        theme_color: body
+            .get("theme_color")
+            .and_then(|v| v.as_str())
+            .map(str::to_string),
+        display: body.get("display").and_then(|v| v.as_str()).map(str::to_string),
+        icons,
+        shortcuts: body
+            .get("shortcuts")
+            .and_then(|v| v.as_array())
+            .map(|shortcuts| {
+                shortcuts
+                    .iter()
+                    .filter_map(|s| s.get("name").and_then(|n| n.as_str()).map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default(),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -111,7 +111,7 @@ mod macro_generated {
 
     use meta_oxide::microformat_extractor;
 
-    #[derive(Debug, Default, PartialEq)]
+    #[derive(Debug, Default, PartialEq, serde::Serialize)]
     pub struct Product {
         pub name: Option<String>,
         pub description: Option<String>,
@@ -8,7 +8,7 @@
 use meta_oxide::microformat_extractor;
 
 /// Simple card structure for demonstration
-#[derive(Debug, Default, PartialEq)]
+#[derive(Debug, Default, PartialEq, serde::Serialize)]
 struct SimpleCard {
     name: Option<String>,
     url: Option<String>,
@@ -0,0 +1,135 @@
+//! Trait-based extractor registry.
+//!
+//! Every format used to be a hardcoded free function, with `extract_all`
+//! calling each one by hand. This module gives each extractor a uniform
+//! `Extractor` shape and a `Registry` that holds them, so adding a new
+//! format means registering it once instead of editing every call site
+//! that iterates "all the extractors".
+
+use serde_json::Value;
+
+use crate::parser::Document;
+use crate::Result;
+
+/// A single metadata extractor: something that can look at a parsed
+/// document and produce a JSON value for its format.
+pub trait Extractor {
+    /// The format's name, e.g. `"meta"`, `"open_graph"`, `"json_ld"`.
+    fn name(&self) -> &str;
+
+    /// Run the extractor against `doc`, resolving relative URLs against
+    /// `base` where applicable.
+    fn extract(&self, doc: &Document, base: Option<&str>) -> Result<Value>;
+}
+
+/// Adapts one of the crate's existing `fn(&Document, Option<&str>) ->
+/// Result<T>` extractors into an `Extractor`, so built-ins don't need to be
+/// rewritten just to join the registry.
+struct FnExtractor<F> {
+    name: &'static str,
+    extract_fn: F,
+}
+
+impl<F, T> Extractor for FnExtractor<F>
+where
+    F: Fn(&Document, Option<&str>) -> Result<T>,
+    T: serde::Serialize,
+{
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn extract(&self, doc: &Document, base: Option<&str>) -> Result<Value> {
+        let value = (self.extract_fn)(doc, base)?;
+        serde_json::to_value(value).map_err(|e| crate::Error::Extraction(e.to_string()))
+    }
+}
+
+fn fn_extractor<F, T>(name: &'static str, extract_fn: F) -> Box<dyn Extractor>
+where
+    F: Fn(&Document, Option<&str>) -> Result<T> + 'static,
+    T: serde::Serialize + 'static,
+{
+    Box::new(FnExtractor { name, extract_fn })
+}
+
+/// Holds the set of extractors that will run over a document.
+///
+/// `Registry::default()` pre-registers all 11 built-in formats; call
+/// [`Registry::register`] to add custom ones on top.
+pub struct Registry {
+    extractors: Vec<Box<dyn Extractor>>,
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        let extractors: Vec<Box<dyn Extractor>> = vec![
+            fn_extractor("meta", crate::extractors::meta::extract),
+            fn_extractor("open_graph", crate::extractors::open_graph::extract),
+            fn_extractor("twitter", crate::extractors::twitter::extract),
+            fn_extractor("json_ld", crate::extractors::json_ld::extract),
+            fn_extractor("microdata", crate::extractors::microdata::extract),
+            fn_extractor("microformats", crate::extractors::microformats::extract),
+            fn_extractor("rdfa", crate::extractors::rdfa::extract),
+            fn_extractor("dublin_core", crate::extractors::dublin_core::extract),
+            fn_extractor("manifest", crate::extractors::manifest::extract),
+            fn_extractor("oembed", crate::extractors::oembed::extract),
+            fn_extractor("rel_links", crate::extractors::rel_links::extract),
+        ];
+        Self { extractors }
+    }
+}
+
+impl Registry {
+    /// An empty registry with no extractors pre-registered.
+    pub fn empty() -> Self {
+        Self {
+            extractors: Vec::new(),
+        }
+    }
+
+    /// Add a user-defined extractor.
+    pub fn register(&mut self, extractor: Box<dyn Extractor>) {
+        self.extractors.push(extractor);
+    }
+
+    /// Every registered extractor, in registration order.
+    pub fn extractors(&self) -> &[Box<dyn Extractor>] {
+        &self.extractors
+    }
+
+    /// Only the extractors whose name is in `names`, for cheaper partial
+    /// runs when a caller needs a couple of formats rather than all of them.
+    pub fn select<'a>(&'a self, names: &[&str]) -> Vec<&'a dyn Extractor> {
+        self.extractors
+            .iter()
+            .map(|e| e.as_ref())
+            .filter(|e| names.contains(&e.name()))
+            .collect()
+    }
+
+    /// Run every registered extractor against `doc`, skipping any that
+    /// error rather than failing the whole run.
+    pub fn extract_all(&self, doc: &Document, base: Option<&str>) -> Vec<(String, Value)> {
+        self.extractors
+            .iter()
+            .filter_map(|e| e.extract(doc, base).ok().map(|v| (e.name().to_string(), v)))
+            .collect()
+    }
+
+    /// Lazily extract each registered (or `only`-filtered) format, one at a
+    /// time, instead of eagerly running and serializing all of them up
+    /// front. Lets a caller stop early, or start acting on the first
+    /// formats while later ones are still running.
+    pub fn extract_stream<'a>(
+        &'a self,
+        doc: &'a Document,
+        base: Option<&'a str>,
+        only: Option<&'a [&'a str]>,
+    ) -> impl Iterator<Item = (String, Result<Value>)> + 'a {
+        self.extractors
+            .iter()
+            .filter(move |e| only.map(|names| names.contains(&e.name())).unwrap_or(true))
+            .map(move |e| (e.name().to_string(), e.extract(doc, base)))
+    }
+}
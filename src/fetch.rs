@@ -0,0 +1,45 @@
+//! Pluggable HTTP fetching for `extract_from_url`, gated behind the
+//! `fetch` cargo feature so the core crate stays dependency-light when a
+//! caller already has the HTML in hand.
+
+use async_trait::async_trait;
+
+use crate::Result;
+
+/// One fetched page: its body, and the URL it was actually served from
+/// after following any redirects (used as `base_url` for relative-link
+/// resolution, since that's the URL relative links are relative to).
+pub struct FetchedPage {
+    pub body: String,
+    pub final_url: String,
+}
+
+/// A pluggable HTTP transport for `extract_from_url`.
+///
+/// The default is [`ReqwestFetcher`]; implement this yourself to point at
+/// a caching layer, a test fixture, or a non-`reqwest` HTTP stack.
+#[async_trait]
+pub trait Fetcher {
+    async fn fetch(&self, url: &str) -> Result<FetchedPage>;
+}
+
+/// The default [`Fetcher`], backed by `reqwest`.
+#[derive(Debug, Default, Clone)]
+pub struct ReqwestFetcher;
+
+#[async_trait]
+impl Fetcher for ReqwestFetcher {
+    async fn fetch(&self, url: &str) -> Result<FetchedPage> {
+        let response = reqwest::get(url)
+            .await
+            .map_err(|e| crate::Error::Extraction(format!("request to {url} failed: {e}")))?;
+
+        let final_url = response.url().to_string();
+        let body = response
+            .text()
+            .await
+            .map_err(|e| crate::Error::Extraction(format!("failed to read response body: {e}")))?;
+
+        Ok(FetchedPage { body, final_url })
+    }
+}
@@ -0,0 +1,190 @@
+//! microformats2 implied-property resolution.
+//!
+//! When a root element has no explicit `p-name`/`u-url`/`u-photo` child,
+//! the mf2 spec still defines a value for it from the shape of the markup
+//! itself. These are "implied" because interoperable parsers have to agree
+//! on the same single-candidate rules, or the same page parses differently
+//! depending on which library reads it.
+
+use scraper::{ElementRef, Selector};
+
+/// True if `element` has no `p-*`/`u-*`/`dt-*`/`e-*` child and isn't itself
+/// a nested microformat root — the precondition for every implied property.
+fn has_no_explicit_properties(element: &ElementRef) -> bool {
+    let Ok(property_selector) = Selector::parse(
+        "[class*=\"p-\"], [class*=\"u-\"], [class*=\"dt-\"], [class*=\"e-\"]",
+    ) else {
+        return true;
+    };
+    element.select(&property_selector).next().is_none()
+}
+
+pub(crate) fn is_microformat_root(element: &ElementRef) -> bool {
+    element
+        .value()
+        .attr("class")
+        .map(|classes| classes.split_whitespace().any(|c| c.starts_with("h-")))
+        .unwrap_or(false)
+}
+
+/// Implied **name**: `img[alt]`, else `abbr[title]`, else `area[alt]`, else
+/// the element's own trimmed text — but only when there's no nested
+/// microformat and no explicit `p-*` property to prefer instead.
+pub fn implied_name(element: &ElementRef) -> Option<String> {
+    if !has_no_explicit_properties(element) {
+        return None;
+    }
+
+    if element.value().name() == "img" {
+        return element.value().attr("alt").map(str::to_string);
+    }
+    if element.value().name() == "abbr" {
+        return element.value().attr("title").map(str::to_string);
+    }
+    if let Some(title) = single_candidate_attr(element, "abbr", "title") {
+        return Some(title);
+    }
+    if element.value().name() == "area" {
+        return element.value().attr("alt").map(str::to_string);
+    }
+    if let Some(alt) = single_candidate_attr(element, "area", "alt") {
+        return Some(alt);
+    }
+
+    crate::html_utils::extract_text(element)
+}
+
+/// Implied **photo**: the element's own `src` if it's an `img`, else the
+/// `src` of its one-and-only descendant `img` that isn't itself part of a
+/// nested microformat.
+pub fn implied_photo(element: &ElementRef) -> Option<String> {
+    if element.value().name() == "img" {
+        return element.value().attr("src").map(str::to_string);
+    }
+
+    let Ok(img_selector) = Selector::parse("img") else {
+        return None;
+    };
+    let mut candidates = element
+        .select(&img_selector)
+        .filter(|img| !has_microformat_ancestor_between(element, img));
+
+    let first = candidates.next()?;
+    if candidates.next().is_some() {
+        return None;
+    }
+    first.value().attr("src").map(str::to_string)
+}
+
+/// Implied **url**: the element's own `href` if it's an `a`/`area`, else
+/// the `href` of its one-and-only descendant `a`/`area` that isn't itself a
+/// nested microformat root.
+pub fn implied_url(element: &ElementRef) -> Option<String> {
+    if matches!(element.value().name(), "a" | "area") {
+        return element.value().attr("href").map(str::to_string);
+    }
+
+    let Ok(link_selector) = Selector::parse("a[href], area[href]") else {
+        return None;
+    };
+    let mut candidates = element
+        .select(&link_selector)
+        .filter(|link| !is_microformat_root(link) && !has_microformat_ancestor_between(element, link));
+
+    let first = candidates.next()?;
+    if candidates.next().is_some() {
+        return None;
+    }
+    first.value().attr("href").map(str::to_string)
+}
+
+/// The `attr` of the one-and-only `tag` descendant that isn't itself a
+/// nested microformat root or beyond one — same single-candidate,
+/// stop-at-nested-root invariants as [`implied_photo`]/[`implied_url`], so a
+/// second nested `h-*` doesn't leak its `tag[attr]` up into this element's
+/// implied name.
+fn single_candidate_attr(element: &ElementRef, tag: &str, attr: &str) -> Option<String> {
+    let selector = Selector::parse(&format!("{tag}[{attr}]")).ok()?;
+    let mut candidates = element
+        .select(&selector)
+        .filter(|candidate| !is_microformat_root(candidate) && !has_microformat_ancestor_between(element, candidate));
+
+    let first = candidates.next()?;
+    if candidates.next().is_some() {
+        return None;
+    }
+    first.value().attr(attr).map(str::to_string)
+}
+
+/// Whether any element strictly between `root` and `descendant` is itself
+/// a nested microformat root — the "stop at nested roots" invariant.
+pub(crate) fn has_microformat_ancestor_between(root: &ElementRef, descendant: &ElementRef) -> bool {
+    descendant
+        .ancestors()
+        .filter_map(ElementRef::wrap)
+        .take_while(|ancestor| ancestor.id() != root.id())
+        .any(|ancestor| is_microformat_root(&ancestor))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scraper::Html;
+
+    fn first_element(document: &Html, selector: &str) -> ElementRef<'_> {
+        let selector = Selector::parse(selector).unwrap();
+        document.select(&selector).next().unwrap()
+    }
+
+    #[test]
+    fn single_candidate_attr_rejects_ambiguous_candidates() {
+        let html = Html::parse_fragment(
+            r#"<div class="h-card"><abbr title="First">F</abbr><abbr title="Second">S</abbr></div>"#,
+        );
+        let root = first_element(&html, ".h-card");
+        assert_eq!(single_candidate_attr(&root, "abbr", "title"), None);
+    }
+
+    #[test]
+    fn single_candidate_attr_stops_at_nested_microformat_root() {
+        let html = Html::parse_fragment(
+            r#"<div class="h-card"><div class="h-card"><abbr title="Nested">N</abbr></div></div>"#,
+        );
+        let root = first_element(&html, ".h-card");
+        assert_eq!(single_candidate_attr(&root, "abbr", "title"), None);
+    }
+
+    #[test]
+    fn single_candidate_attr_returns_the_one_unambiguous_match() {
+        let html = Html::parse_fragment(r#"<div class="h-card"><abbr title="Jane Doe">JD</abbr></div>"#);
+        let root = first_element(&html, ".h-card");
+        assert_eq!(
+            single_candidate_attr(&root, "abbr", "title"),
+            Some("Jane Doe".to_string())
+        );
+    }
+
+    #[test]
+    fn implied_name_uses_the_root_elements_own_abbr_title() {
+        // The canonical mf2 example: the h-card root is itself the `abbr`,
+        // so there's no descendant to select and the implied name must come
+        // from this element's own `title`, not its text content.
+        let html = Html::parse_fragment(r#"<abbr class="h-card" title="Jane Doe">JD</abbr>"#);
+        let root = first_element(&html, ".h-card");
+        assert_eq!(implied_name(&root), Some("Jane Doe".to_string()));
+    }
+
+    #[test]
+    fn implied_name_uses_the_root_elements_own_img_alt() {
+        let html = Html::parse_fragment(r#"<img class="h-card" alt="Jane Doe">"#);
+        let root = first_element(&html, ".h-card");
+        assert_eq!(implied_name(&root), Some("Jane Doe".to_string()));
+    }
+
+    #[test]
+    fn implied_name_uses_the_root_elements_own_area_alt() {
+        let html = Html::parse_fragment(r#"<area class="h-card" alt="Jane Doe">"#);
+        let root = first_element(&html, ".h-card");
+        assert_eq!(implied_name(&root), Some("Jane Doe".to_string()));
+    }
+}
@@ -0,0 +1,277 @@
+//! Canonical microformats2 JSON, as consumed by the IndieWeb ecosystem
+//! (Micropub servers, h-feed readers, ...).
+//!
+//! `extractors::microformats` already returns one JSON object per h-*
+//! root, but in a crate-specific shape. This module reshapes that into the
+//! canonical mf2 structure instead:
+//!
+//! ```json
+//! {
+//!   "items": [{ "type": ["h-entry"], "properties": { "name": ["..."] }, "children": [] }],
+//!   "rels": { "author": ["https://example.com/about"] },
+//!   "rel-urls": { "https://example.com/about": { "rels": ["author"], "text": "About" } }
+//! }
+//! ```
+//!
+//! where every property value is an array, per the mf2 parsing rules.
+
+use scraper::{ElementRef, Selector};
+use serde_json::{json, Map, Value};
+
+use crate::parser::Document;
+use crate::Result;
+
+/// Build the canonical mf2 JSON document for `doc`.
+pub fn to_mf2_json(doc: &Document, base: Option<&str>) -> Result<Value> {
+    let microformats = crate::extractors::microformats::extract(doc, base)?;
+    let raw_items = serde_json::to_value(&microformats).unwrap_or(Value::Null);
+
+    let mut roots = top_level_microformat_roots(doc).into_iter();
+
+    let items = match &raw_items {
+        Value::Array(items) => items
+            .iter()
+            .map(|item| canonicalize_item(item, roots.next().as_ref(), base))
+            .collect(),
+        Value::Object(_) => vec![canonicalize_item(&raw_items, roots.next().as_ref(), base)],
+        _ => Vec::new(),
+    };
+
+    let rel_links = crate::rel::parse(doc, base)?;
+
+    Ok(json!({
+        "items": items,
+        "rels": rel_links.rels,
+        "rel-urls": rel_links.rel_urls,
+    }))
+}
+
+/// Every top-level `h-*` root in `doc`, in document order — i.e. every
+/// element with an `h-*` class that isn't itself nested inside another one.
+/// Paired positionally against `extractors::microformats::extract`'s output
+/// (which walks the same roots in the same order) so `canonicalize_item`
+/// has the DOM element behind each item available for implied-property
+/// resolution.
+fn top_level_microformat_roots(doc: &Document) -> Vec<ElementRef<'_>> {
+    let Ok(selector) = Selector::parse("[class*=\"h-\"]") else {
+        return Vec::new();
+    };
+    doc.select(&selector)
+        .filter(|element| {
+            element
+                .ancestors()
+                .filter_map(ElementRef::wrap)
+                .all(|ancestor| !crate::implied::is_microformat_root(&ancestor))
+        })
+        .collect()
+}
+
+/// The nested `h-*` roots directly inside `element` — i.e. not themselves
+/// nested inside a closer `h-*` root. Paired positionally against an
+/// item's `children` array for the same reason as
+/// [`top_level_microformat_roots`].
+fn nested_microformat_roots<'a>(element: &ElementRef<'a>) -> Vec<ElementRef<'a>> {
+    let Ok(selector) = Selector::parse("[class*=\"h-\"]") else {
+        return Vec::new();
+    };
+    element
+        .select(&selector)
+        .filter(|candidate| !crate::implied::has_microformat_ancestor_between(element, candidate))
+        .collect()
+}
+
+/// Reshape one crate-internal microformat item into the canonical
+/// `{type, properties, children}` form, implying `name`/`photo`/`url`
+/// where they weren't already filled in by the extractor. `element` is the
+/// DOM node the item was extracted from, when known, and is what implying
+/// those properties needs; `base` resolves any implied `url`/`photo`
+/// against, same as the extractor does for its own explicit properties.
+fn canonicalize_item(item: &Value, element: Option<&ElementRef>, base: Option<&str>) -> Value {
+    let obj = match item.as_object() {
+        Some(obj) => obj,
+        None => return item.clone(),
+    };
+
+    let types = match obj.get("type").or_else(|| obj.get("types")) {
+        Some(Value::Array(types)) => types.clone(),
+        Some(Value::String(t)) => vec![Value::String(t.clone())],
+        _ => Vec::new(),
+    };
+
+    let mut properties = Map::new();
+    if let Some(Value::Object(props)) = obj.get("properties") {
+        for (key, value) in props {
+            properties.insert(key.clone(), as_property_array(value, base));
+        }
+    } else {
+        // Flat crate-specific fields (name, url, photo, ...) rather than an
+        // already-nested `properties` object.
+        for (key, value) in obj {
+            if matches!(key.as_str(), "type" | "types" | "children" | "properties") {
+                continue;
+            }
+            if value.is_null() {
+                continue;
+            }
+            properties.insert(key.clone(), as_property_array(value, base));
+        }
+    }
+
+    if let Some(element) = element {
+        imply_missing_properties(&mut properties, element, base);
+    }
+
+    let children = match obj.get("children") {
+        Some(Value::Array(children)) => {
+            let mut child_roots = element.map(nested_microformat_roots).unwrap_or_default().into_iter();
+            children
+                .iter()
+                .map(|child| canonicalize_item(child, child_roots.next().as_ref(), base))
+                .collect()
+        }
+        _ => Vec::new(),
+    };
+
+    json!({
+        "type": types,
+        "properties": properties,
+        "children": children,
+    })
+}
+
+/// Fill `name`/`photo`/`url` via the mf2 implied-property algorithm for
+/// whichever of them the extractor left out, resolving `photo`/`url`
+/// against `base` the same way the extractor resolves its own explicit
+/// `u-photo`/`u-url` properties.
+fn imply_missing_properties(properties: &mut Map<String, Value>, element: &ElementRef, base: Option<&str>) {
+    if !properties.contains_key("name") {
+        if let Some(name) = crate::implied::implied_name(element) {
+            properties.insert("name".to_string(), Value::Array(vec![Value::String(name)]));
+        }
+    }
+    if !properties.contains_key("photo") {
+        if let Some(photo) = crate::implied::implied_photo(element) {
+            properties.insert(
+                "photo".to_string(),
+                Value::Array(vec![Value::String(resolve_or_raw(base, &photo))]),
+            );
+        }
+    }
+    if !properties.contains_key("url") {
+        if let Some(url) = crate::implied::implied_url(element) {
+            properties.insert(
+                "url".to_string(),
+                Value::Array(vec![Value::String(resolve_or_raw(base, &url))]),
+            );
+        }
+    }
+}
+
+/// Resolve `raw` against `base`, falling back to the raw value when
+/// resolution fails (no base, or an unparseable URL) instead of dropping it.
+fn resolve_or_raw(base: Option<&str>, raw: &str) -> String {
+    crate::url_utils::resolve_url(base, raw)
+        .ok()
+        .unwrap_or_else(|| raw.to_string())
+}
+
+fn as_property_array(value: &Value, base: Option<&str>) -> Value {
+    match value {
+        Value::Array(_) => value.clone(),
+        Value::Null => Value::Array(Vec::new()),
+        Value::Object(obj) if obj.contains_key("type") && obj.contains_key("properties") => {
+            // No DOM element is paired with a nested item reached through a
+            // property value (as opposed to `children`), so it can't have
+            // its own implied properties resolved here.
+            Value::Array(vec![canonicalize_item(value, None, base)])
+        }
+        other => Value::Array(vec![other.clone()]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scraper::Html;
+    use serde_json::json;
+
+    fn first_element(document: &Html, selector: &str) -> ElementRef<'_> {
+        let selector = Selector::parse(selector).unwrap();
+        document.select(&selector).next().unwrap()
+    }
+
+    #[test]
+    fn imply_missing_properties_fills_photo_left_out_by_the_extractor() {
+        let html = Html::parse_fragment(
+            r#"<div class="h-card"><img src="https://example.com/photo.jpg"></div>"#,
+        );
+        let root = first_element(&html, ".h-card");
+        let mut properties = Map::new();
+
+        imply_missing_properties(&mut properties, &root, None);
+
+        assert_eq!(
+            properties.get("photo"),
+            Some(&json!(["https://example.com/photo.jpg"]))
+        );
+    }
+
+    #[test]
+    fn imply_missing_properties_resolves_a_relative_url_against_base() {
+        let html = Html::parse_fragment(r#"<div class="h-card"><a href="/about">Example</a></div>"#);
+        let root = first_element(&html, ".h-card");
+        let mut properties = Map::new();
+
+        imply_missing_properties(&mut properties, &root, Some("https://example.com/page"));
+
+        assert_eq!(
+            properties.get("url"),
+            Some(&json!(["https://example.com/about"]))
+        );
+    }
+
+    #[test]
+    fn canonicalize_item_implies_url_missing_from_the_extractor() {
+        let html =
+            Html::parse_fragment(r#"<div class="h-card"><a href="https://example.com">Example</a></div>"#);
+        let root = first_element(&html, ".h-card");
+        let item = json!({ "type": ["h-card"], "properties": {} });
+
+        let canonical = canonicalize_item(&item, Some(&root), None);
+
+        assert_eq!(canonical["properties"]["url"], json!(["https://example.com"]));
+    }
+
+    #[test]
+    fn canonicalize_item_resolves_an_implied_url_against_base() {
+        let html = Html::parse_fragment(r#"<div class="h-card"><a href="/about">Example</a></div>"#);
+        let root = first_element(&html, ".h-card");
+        let item = json!({ "type": ["h-card"], "properties": {} });
+
+        let canonical = canonicalize_item(&item, Some(&root), Some("https://example.com/page"));
+
+        assert_eq!(
+            canonical["properties"]["url"],
+            json!(["https://example.com/about"])
+        );
+    }
+
+    #[test]
+    fn canonicalize_item_leaves_an_explicit_property_untouched() {
+        let html = Html::parse_fragment(
+            r#"<div class="h-card"><a href="https://example.com/other">Example</a></div>"#,
+        );
+        let root = first_element(&html, ".h-card");
+        let item = json!({
+            "type": ["h-card"],
+            "properties": { "url": ["https://example.com/explicit"] },
+        });
+
+        let canonical = canonicalize_item(&item, Some(&root), None);
+
+        assert_eq!(
+            canonical["properties"]["url"],
+            json!(["https://example.com/explicit"])
+        );
+    }
+}
@@ -0,0 +1,106 @@
+//! Expands JSON-LD documents into triples.
+//!
+//! We don't implement the full JSON-LD expansion algorithm (contexts,
+//! `@reverse`, framing, ...) here — that belongs in `extractors::json_ld`.
+//! This module only walks the already-parsed documents it returns and turns
+//! each property into one triple, recursing into nested objects and arrays.
+
+use oxrdf::{Graph, NamedNode};
+use serde_json::Value;
+
+use super::{expand_iri, insert_triple, literal_term, subject_for, RDF_TYPE};
+use crate::Result;
+
+pub(super) fn add_triples(graph: &mut Graph, docs: &Value, base: Option<&str>) -> Result<()> {
+    match docs {
+        Value::Array(items) => {
+            for item in items {
+                walk(graph, item, base);
+            }
+        }
+        Value::Object(_) => walk(graph, docs, base),
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Walk one JSON-LD node, returning the subject it was emitted as so a
+/// parent can link to it as an object.
+fn walk(graph: &mut Graph, node: &Value, base: Option<&str>) -> Option<oxrdf::Subject> {
+    let obj = node.as_object()?;
+    let id = obj.get("@id").and_then(Value::as_str);
+    let subject = subject_for(id, base);
+
+    if let Ok(rdf_type) = NamedNode::new(RDF_TYPE) {
+        for type_iri in type_iris(obj.get("@type")) {
+            if let Ok(object) = NamedNode::new(type_iri) {
+                insert_triple(graph, subject.clone(), rdf_type.clone(), object.into());
+            }
+        }
+    }
+
+    for (key, value) in obj {
+        if key.starts_with('@') {
+            continue;
+        }
+        let Ok(predicate) = NamedNode::new(expand_key(key, base)) else {
+            continue;
+        };
+
+        match value {
+            Value::Array(values) => {
+                for v in values {
+                    add_property(graph, &subject, &predicate, v, base);
+                }
+            }
+            other => add_property(graph, &subject, &predicate, other, base),
+        }
+    }
+
+    Some(subject)
+}
+
+fn add_property(
+    graph: &mut Graph,
+    subject: &oxrdf::Subject,
+    predicate: &NamedNode,
+    value: &Value,
+    base: Option<&str>,
+) {
+    if value.is_object() {
+        if let Some(child_subject) = walk(graph, value, base) {
+            insert_triple(
+                graph,
+                subject.clone(),
+                predicate.clone(),
+                child_subject.into(),
+            );
+        }
+        return;
+    }
+
+    if let Some(term) = literal_term(value) {
+        insert_triple(graph, subject.clone(), predicate.clone(), term);
+    }
+}
+
+/// A bare property name with no `@context` to expand against still needs to
+/// become *some* IRI; delegate to the same fallback Microdata uses so the
+/// same property expressed in both formats lands on one predicate.
+fn expand_key(key: &str, _base: Option<&str>) -> String {
+    expand_iri(key)
+}
+
+/// `@type` is a single string, an array of strings, or absent; expand each
+/// one the same way a property key would.
+fn type_iris(type_value: Option<&Value>) -> Vec<String> {
+    match type_value {
+        Some(Value::String(t)) => vec![expand_iri(t)],
+        Some(Value::Array(values)) => values
+            .iter()
+            .filter_map(Value::as_str)
+            .map(expand_iri)
+            .collect(),
+        _ => Vec::new(),
+    }
+}
@@ -0,0 +1,75 @@
+//! Maps Microdata items (`itemscope`/`itemprop`/`itemtype`) into triples.
+//!
+//! Each `itemscope` becomes a subject (its `itemid`, if present, or a fresh
+//! blank node); its `itemtype` becomes an `rdf:type` triple; each `itemprop`
+//! becomes a predicate expanded the same way JSON-LD expands a bare
+//! property name, rather than the strict Microdata-to-RDF namespacing of
+//! `itemtype#property` — pages commonly mark the same entity up with both
+//! JSON-LD and Microdata, and this is what lets the two merge into one
+//! triple instead of two differently-named ones; nested itemscopes become
+//! object nodes linked back to their parent.
+
+use oxrdf::{Graph, NamedNode};
+use serde_json::Value;
+
+use super::{expand_iri, insert_triple, literal_term, subject_for, RDF_TYPE};
+use crate::Result;
+
+pub(super) fn add_triples(graph: &mut Graph, items: &Value, base: Option<&str>) -> Result<()> {
+    match items {
+        Value::Array(items) => {
+            for item in items {
+                walk(graph, item, base);
+            }
+        }
+        Value::Object(_) => {
+            walk(graph, items, base);
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+fn walk(graph: &mut Graph, item: &Value, base: Option<&str>) -> Option<oxrdf::Subject> {
+    let obj = item.as_object()?;
+    let itemid = obj.get("itemid").and_then(Value::as_str);
+    let itemtype = obj.get("itemtype").and_then(Value::as_str).unwrap_or("");
+    let subject = subject_for(itemid, base);
+
+    if let Ok(rdf_type) = NamedNode::new(RDF_TYPE) {
+        for type_iri in itemtype.split_whitespace() {
+            if let Ok(object) = NamedNode::new(type_iri) {
+                insert_triple(graph, subject.clone(), rdf_type.clone(), object.into());
+            }
+        }
+    }
+
+    let properties = obj.get("properties").and_then(Value::as_object)?;
+    for (name, values) in properties {
+        let Ok(predicate) = NamedNode::new(expand_iri(name)) else {
+            continue;
+        };
+
+        let values = match values {
+            Value::Array(values) => values.clone(),
+            other => vec![other.clone()],
+        };
+
+        for value in &values {
+            if value.get("properties").is_some() {
+                if let Some(child_subject) = walk(graph, value, base) {
+                    insert_triple(
+                        graph,
+                        subject.clone(),
+                        predicate.clone(),
+                        child_subject.into(),
+                    );
+                }
+            } else if let Some(term) = literal_term(value) {
+                insert_triple(graph, subject.clone(), predicate.clone(), term);
+            }
+        }
+    }
+
+    Some(subject)
+}
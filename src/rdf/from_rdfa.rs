@@ -0,0 +1,55 @@
+//! Folds RDFa's already subject/predicate/object items into the graph.
+//!
+//! Unlike JSON-LD and Microdata, the RDFa extractor applies the RDFa
+//! processing rules itself and hands back items that are already triples in
+//! spirit; this module just lifts them into `oxrdf` terms.
+
+use oxrdf::{Graph, NamedNode};
+use serde_json::Value;
+
+use super::{insert_triple, literal_term, subject_for};
+use crate::Result;
+
+pub(super) fn add_triples(graph: &mut Graph, items: &Value, base: Option<&str>) -> Result<()> {
+    let Value::Array(triples) = items else {
+        return Ok(());
+    };
+
+    for triple in triples {
+        let Some(obj) = triple.as_object() else {
+            continue;
+        };
+        let subject_id = obj.get("subject").and_then(Value::as_str);
+        let Some(predicate_str) = obj.get("predicate").and_then(Value::as_str) else {
+            continue;
+        };
+        let Ok(predicate) = NamedNode::new(predicate_str) else {
+            continue;
+        };
+
+        let subject = subject_for(subject_id, base);
+
+        let object = obj.get("object");
+        let is_resource = obj
+            .get("object_type")
+            .and_then(Value::as_str)
+            .map(|t| t == "resource" || t == "IRI")
+            .unwrap_or(false);
+
+        match (object, is_resource) {
+            (Some(Value::String(iri)), true) => {
+                if let Ok(named) = NamedNode::new(iri) {
+                    insert_triple(graph, subject, predicate, named.into());
+                }
+            }
+            (Some(value), false) => {
+                if let Some(term) = literal_term(value) {
+                    insert_triple(graph, subject, predicate, term);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,233 @@
+//! Unified RDF graph built from the structured-data extractors.
+//!
+//! `extractors::json_ld`, `extractors::microdata`, and `extractors::rdfa` all
+//! describe subject/predicate/object data, just in three different surface
+//! syntaxes. This module normalizes all three into a single `oxrdf::Graph`
+//! so callers can serialize the merged result (Turtle, N-Triples) or run
+//! SPARQL `SELECT`/`ASK` queries over it instead of walking three separate
+//! JSON shapes by hand.
+
+use oxrdf::{BlankNode, Graph, Literal, NamedNode, Subject, Term, Triple};
+use serde_json::Value;
+use spargebra::Query;
+
+use crate::parser::Document;
+use crate::{Error, Result};
+
+mod from_json_ld;
+mod from_microdata;
+mod from_rdfa;
+
+/// The `rdf:type` predicate IRI, so `?s a schema:Person`-style SPARQL
+/// queries have something to match against regardless of which format a
+/// page used to declare its type.
+pub(crate) const RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+
+/// Build a merged RDF graph from every structured-data format found in `doc`.
+///
+/// Triples contributed by JSON-LD, Microdata, and RDFa are deduplicated as
+/// they're inserted, since the same fact is frequently expressed in more
+/// than one format on the same page.
+pub fn extract_rdf(doc: &Document, base: Option<&str>) -> Result<Graph> {
+    let mut graph = Graph::new();
+
+    // Each extractor keeps its own concrete return type; we only need the
+    // JSON shape here, so go through `serde_json::to_value` rather than
+    // depending on those types directly.
+    if let Ok(docs) = crate::extractors::json_ld::extract(doc, base) {
+        if let Ok(value) = serde_json::to_value(&docs) {
+            from_json_ld::add_triples(&mut graph, &value, base)?;
+        }
+    }
+    if let Ok(items) = crate::extractors::microdata::extract(doc, base) {
+        if let Ok(value) = serde_json::to_value(&items) {
+            from_microdata::add_triples(&mut graph, &value, base)?;
+        }
+    }
+    if let Ok(items) = crate::extractors::rdfa::extract(doc, base) {
+        if let Ok(value) = serde_json::to_value(&items) {
+            from_rdfa::add_triples(&mut graph, &value, base)?;
+        }
+    }
+
+    Ok(graph)
+}
+
+/// Serialize a graph as Turtle.
+pub fn to_turtle(graph: &Graph) -> Result<String> {
+    let mut out = Vec::new();
+    graph
+        .serialize(&mut out, oxrdfio::RdfFormat::Turtle)
+        .map_err(|e| Error::Extraction(format!("turtle serialization failed: {e}")))?;
+    String::from_utf8(out).map_err(|e| Error::Extraction(e.to_string()))
+}
+
+/// Serialize a graph as N-Triples.
+pub fn to_ntriples(graph: &Graph) -> Result<String> {
+    let mut out = Vec::new();
+    graph
+        .serialize(&mut out, oxrdfio::RdfFormat::NTriples)
+        .map_err(|e| Error::Extraction(format!("n-triples serialization failed: {e}")))?;
+    String::from_utf8(out).map_err(|e| Error::Extraction(e.to_string()))
+}
+
+/// Result rows from a SPARQL `SELECT`, or the single boolean from an `ASK`.
+pub enum QueryResult {
+    Select {
+        variables: Vec<String>,
+        rows: Vec<Vec<Option<Term>>>,
+    },
+    Ask(bool),
+}
+
+/// Run a SPARQL `SELECT` or `ASK` query against a graph.
+pub fn query(graph: &Graph, sparql: &str) -> Result<QueryResult> {
+    let query = Query::parse(sparql, None)
+        .map_err(|e| Error::Extraction(format!("invalid SPARQL query: {e}")))?;
+
+    // spargebra only parses; evaluation is delegated to oxigraph's
+    // in-memory store so we don't have to reimplement the algebra here.
+    let store = oxigraph::store::Store::new()
+        .map_err(|e| Error::Extraction(format!("failed to build query store: {e}")))?;
+    for triple in graph.iter() {
+        store
+            .insert(triple.into_owned().as_ref())
+            .map_err(|e| Error::Extraction(format!("failed to load graph for query: {e}")))?;
+    }
+
+    match store
+        .query(query)
+        .map_err(|e| Error::Extraction(format!("query evaluation failed: {e}")))?
+    {
+        oxigraph::sparql::QueryResults::Solutions(solutions) => {
+            let variables: Vec<String> = solutions
+                .variables()
+                .iter()
+                .map(|v| v.as_str().to_string())
+                .collect();
+            let mut rows = Vec::new();
+            for solution in solutions {
+                let solution = solution.map_err(|e| Error::Extraction(e.to_string()))?;
+                rows.push(
+                    variables
+                        .iter()
+                        .map(|v| solution.get(v.as_str()).cloned())
+                        .collect(),
+                );
+            }
+            Ok(QueryResult::Select { variables, rows })
+        }
+        oxigraph::sparql::QueryResults::Boolean(b) => Ok(QueryResult::Ask(b)),
+        oxigraph::sparql::QueryResults::Graph(_) => Err(Error::Extraction(
+            "CONSTRUCT/DESCRIBE queries are not supported".into(),
+        )),
+    }
+}
+
+/// Mint a blank node, or a named node when the JSON value carries an `@id`
+/// (JSON-LD) or `itemid` (Microdata) that resolves to an absolute IRI.
+pub(crate) fn subject_for(id: Option<&str>, base: Option<&str>) -> Subject {
+    match id.and_then(|id| crate::url_utils::resolve_url(base, id).ok()) {
+        Some(iri) => NamedNode::new(iri).map(Subject::NamedNode).unwrap_or_else(|_| {
+            Subject::BlankNode(BlankNode::default())
+        }),
+        None => Subject::BlankNode(BlankNode::default()),
+    }
+}
+
+pub(crate) fn literal_term(value: &Value) -> Option<Term> {
+    match value {
+        Value::String(s) => Some(Term::Literal(Literal::new_simple_literal(s))),
+        Value::Number(n) => Some(Term::Literal(Literal::from(n.as_f64()?))),
+        Value::Bool(b) => Some(Term::Literal(Literal::from(*b))),
+        _ => None,
+    }
+}
+
+pub(crate) fn insert_triple(graph: &mut Graph, subject: Subject, predicate: NamedNode, object: Term) {
+    graph.insert(&Triple::new(subject, predicate, object));
+}
+
+/// Expand a bare property name (or type) into an IRI the same way
+/// regardless of which format it came from, so the same fact expressed as
+/// both JSON-LD and Microdata on one page produces one predicate instead of
+/// two — already-absolute values (an `@type`/`itemtype` URL, a JSON-LD key
+/// that's itself an IRI) pass through unchanged; anything else falls back
+/// to a private `schema.org` vocabulary so the triple round-trips instead
+/// of being dropped.
+pub(crate) fn expand_iri(name: &str) -> String {
+    if name.contains("://") {
+        name.to_string()
+    } else {
+        format!("https://schema.org/{name}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn json_ld_and_microdata_agree_on_the_same_predicate() {
+        let mut json_ld_graph = Graph::new();
+        from_json_ld::add_triples(
+            &mut json_ld_graph,
+            &json!({ "@type": "Person", "name": "Jane Doe" }),
+            None,
+        )
+        .unwrap();
+
+        let mut microdata_graph = Graph::new();
+        from_microdata::add_triples(
+            &mut microdata_graph,
+            &json!({
+                "itemtype": "https://schema.org/Person",
+                "properties": { "name": ["Jane Doe"] },
+            }),
+            None,
+        )
+        .unwrap();
+
+        let literal_predicate = |graph: &Graph| -> Option<String> {
+            graph
+                .iter()
+                .find(|t| matches!(t.object, oxrdf::TermRef::Literal(_)))
+                .map(|t| t.predicate.as_str().to_string())
+        };
+
+        let json_ld_predicate = literal_predicate(&json_ld_graph);
+        let microdata_predicate = literal_predicate(&microdata_graph);
+
+        assert_eq!(json_ld_predicate, microdata_predicate);
+        assert_eq!(json_ld_predicate.as_deref(), Some("https://schema.org/name"));
+    }
+
+    fn has_type_triple(graph: &Graph, type_iri: &str) -> bool {
+        graph.iter().any(|t| {
+            t.predicate.as_str() == RDF_TYPE
+                && matches!(t.object, oxrdf::TermRef::NamedNode(n) if n.as_str() == type_iri)
+        })
+    }
+
+    #[test]
+    fn json_ld_emits_rdf_type_for_at_type() {
+        let mut graph = Graph::new();
+        from_json_ld::add_triples(&mut graph, &json!({ "@type": "Person" }), None).unwrap();
+
+        assert!(has_type_triple(&graph, "https://schema.org/Person"));
+    }
+
+    #[test]
+    fn microdata_emits_rdf_type_for_itemtype() {
+        let mut graph = Graph::new();
+        from_microdata::add_triples(
+            &mut graph,
+            &json!({ "itemtype": "https://schema.org/Person", "properties": {} }),
+            None,
+        )
+        .unwrap();
+
+        assert!(has_type_triple(&graph, "https://schema.org/Person"));
+    }
+}
@@ -0,0 +1,181 @@
+//! Top-level `rel`/`rel-urls` parsing.
+//!
+//! Every `<a rel>` and `<link rel>` in a document contributes to two maps
+//! defined by the microformats2 spec: `rels` (rel-value -> URLs that carry
+//! it) and `rel-urls` (URL -> everything else known about that one link —
+//! its rel tokens, `hreflang`, `media`, `title`, `type`, and text). This is
+//! what lets a caller discover feeds, webmention/pingback endpoints, and
+//! authorship links without walking the h-* tree at all.
+
+use std::collections::HashMap;
+
+use scraper::Selector;
+use serde::{Deserialize, Serialize};
+
+use crate::parser::Document;
+use crate::Result;
+
+/// Everything known about one URL that appeared in a `rel` link.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct RelUrlInfo {
+    pub rels: Vec<String>,
+    pub hreflang: Option<String>,
+    pub media: Option<String>,
+    pub title: Option<String>,
+    #[serde(rename = "type")]
+    pub type_: Option<String>,
+    pub text: Option<String>,
+}
+
+/// The `rels`/`rel-urls` parse of a document.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct RelLinks {
+    pub rels: HashMap<String, Vec<String>>,
+    #[serde(rename = "rel-urls")]
+    pub rel_urls: HashMap<String, RelUrlInfo>,
+}
+
+/// Walk every `<a rel>` and `<link rel>` in `doc` and build the `rels`/
+/// `rel-urls` maps, resolving each `href` against `base`.
+pub fn parse(doc: &Document, base: Option<&str>) -> Result<RelLinks> {
+    let selector = Selector::parse("a[rel][href], link[rel][href]")
+        .map_err(|e| crate::Error::Extraction(format!("invalid rel selector: {e}")))?;
+
+    let mut links = RelLinks::default();
+
+    for element in doc.select(&selector) {
+        let Some(href) = element.value().attr("href") else {
+            continue;
+        };
+        let url = resolved_or_raw_url(base, href);
+        let Some(rel) = element.value().attr("rel") else {
+            continue;
+        };
+        let rel_tokens: Vec<String> = rel.split_whitespace().map(str::to_string).collect();
+
+        for token in &rel_tokens {
+            let urls = links.rels.entry(token.clone()).or_default();
+            if !urls.contains(&url) {
+                urls.push(url.clone());
+            }
+        }
+
+        let info = links.rel_urls.entry(url).or_insert_with(|| RelUrlInfo {
+            rels: Vec::new(),
+            hreflang: element.value().attr("hreflang").map(str::to_string),
+            media: element.value().attr("media").map(str::to_string),
+            title: element.value().attr("title").map(str::to_string),
+            type_: element.value().attr("type").map(str::to_string),
+            text: crate::html_utils::extract_text(&element),
+        });
+
+        for token in rel_tokens {
+            if !info.rels.contains(&token) {
+                info.rels.push(token);
+            }
+        }
+    }
+
+    Ok(links)
+}
+
+/// Resolve `href` against `base`, falling back to the raw `href` when
+/// resolution fails (e.g. a relative `href` with no `base`) instead of
+/// dropping the link entirely.
+fn resolved_or_raw_url(base: Option<&str>, href: &str) -> String {
+    crate::url_utils::resolve_url(base, href)
+        .ok()
+        .unwrap_or_else(|| href.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_against_an_absolute_base() {
+        assert_eq!(
+            resolved_or_raw_url(Some("https://example.com/page"), "/feed"),
+            "https://example.com/feed"
+        );
+    }
+
+    #[test]
+    fn falls_back_to_the_raw_href_when_there_is_no_base_to_resolve_against() {
+        assert_eq!(resolved_or_raw_url(None, "/feed"), "/feed");
+    }
+
+    #[test]
+    fn parse_splits_a_multi_token_rel_into_each_of_its_values() {
+        let html = r#"<link rel="alternate feed" href="/feed.xml">"#;
+        let doc = crate::parser::parse_html(html).unwrap();
+
+        let links = parse(&doc, Some("https://example.com/")).unwrap();
+
+        assert_eq!(
+            links.rels.get("alternate"),
+            Some(&vec!["https://example.com/feed.xml".to_string()])
+        );
+        assert_eq!(
+            links.rels.get("feed"),
+            Some(&vec!["https://example.com/feed.xml".to_string()])
+        );
+        assert_eq!(
+            links.rel_urls["https://example.com/feed.xml"].rels,
+            vec!["alternate".to_string(), "feed".to_string()]
+        );
+    }
+
+    #[test]
+    fn parse_collects_the_same_url_under_two_separate_rel_links() {
+        let html = r#"
+            <link rel="alternate" href="/feed.xml">
+            <a rel="bookmark" href="/feed.xml">Permalink</a>
+        "#;
+        let doc = crate::parser::parse_html(html).unwrap();
+
+        let links = parse(&doc, Some("https://example.com/")).unwrap();
+
+        assert_eq!(
+            links.rels.get("alternate"),
+            Some(&vec!["https://example.com/feed.xml".to_string()])
+        );
+        assert_eq!(
+            links.rels.get("bookmark"),
+            Some(&vec!["https://example.com/feed.xml".to_string()])
+        );
+        assert_eq!(
+            links.rel_urls["https://example.com/feed.xml"].rels,
+            vec!["alternate".to_string(), "bookmark".to_string()]
+        );
+    }
+
+    #[test]
+    fn parse_captures_hreflang_media_title_and_type() {
+        let html = r#"<a rel="alternate" href="/de" hreflang="de" media="print" title="Deutsch" type="text/html">DE</a>"#;
+        let doc = crate::parser::parse_html(html).unwrap();
+
+        let links = parse(&doc, Some("https://example.com/")).unwrap();
+
+        let info = &links.rel_urls["https://example.com/de"];
+        assert_eq!(info.hreflang.as_deref(), Some("de"));
+        assert_eq!(info.media.as_deref(), Some("print"));
+        assert_eq!(info.title.as_deref(), Some("Deutsch"));
+        assert_eq!(info.type_.as_deref(), Some("text/html"));
+        assert_eq!(info.text.as_deref(), Some("DE"));
+    }
+
+    #[test]
+    fn parse_ignores_links_with_no_rel_or_no_href() {
+        let html = r#"
+            <a href="/no-rel">No rel</a>
+            <link rel="stylesheet">
+        "#;
+        let doc = crate::parser::parse_html(html).unwrap();
+
+        let links = parse(&doc, None).unwrap();
+
+        assert!(links.rels.is_empty());
+        assert!(links.rel_urls.is_empty());
+    }
+}